@@ -0,0 +1,153 @@
+use crate::error::{err, Error, Result};
+
+/// Derivation codes and their associated raw (decoded) byte sizes.
+///
+/// This mirrors the part of the CESR `Matter` code table this crate
+/// actually exercises: one case-sensitive character selects the
+/// derivation, which in turn fixes the raw length of the material it
+/// prefixes.
+pub mod tables {
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Codex {
+        Ed25519N,
+        Ed25519,
+        ECDSA_256k1N,
+        ECDSA_256k1,
+        ECDSA_256k1_Schnorr,
+        ECDSA_256r1N,
+        ECDSA_256r1,
+        Ed448N,
+        Ed448,
+        Blake3_256,
+    }
+
+    use super::{err, Error, Result};
+
+    impl Codex {
+        pub fn code(&self) -> &'static str {
+            match self {
+                Codex::Ed25519N => "B",
+                Codex::Ed25519 => "D",
+                Codex::ECDSA_256k1N => "J",
+                Codex::ECDSA_256k1 => "K",
+                Codex::ECDSA_256k1_Schnorr => "T",
+                Codex::ECDSA_256r1N => "P",
+                Codex::ECDSA_256r1 => "Q",
+                Codex::Ed448N => "L",
+                Codex::Ed448 => "M",
+                Codex::Blake3_256 => "E",
+            }
+        }
+
+        /// Raw (decoded) size in bytes for material carrying this code.
+        pub fn size(&self) -> u32 {
+            match self {
+                Codex::Ed25519N | Codex::Ed25519 => 32,
+                // compressed SEC1 point: a one-byte parity prefix plus a
+                // 32-byte field element
+                Codex::ECDSA_256k1N | Codex::ECDSA_256k1 => 33,
+                // BIP340 x-only public key
+                Codex::ECDSA_256k1_Schnorr => 32,
+                // compressed SEC1 point, same convention as ECDSA_256k1(N)
+                Codex::ECDSA_256r1N | Codex::ECDSA_256r1 => 33,
+                // ed448-goldilocks VerifyingKey/PointBytes length; the
+                // 114-byte figure sometimes quoted for Ed448 is the
+                // signature length, not the public key
+                Codex::Ed448N | Codex::Ed448 => 57,
+                Codex::Blake3_256 => 32,
+            }
+        }
+
+        pub fn from_code(code: &str) -> Result<Self> {
+            match code {
+                "B" => Ok(Codex::Ed25519N),
+                "D" => Ok(Codex::Ed25519),
+                "J" => Ok(Codex::ECDSA_256k1N),
+                "K" => Ok(Codex::ECDSA_256k1),
+                "T" => Ok(Codex::ECDSA_256k1_Schnorr),
+                "P" => Ok(Codex::ECDSA_256r1N),
+                "Q" => Ok(Codex::ECDSA_256r1),
+                "L" => Ok(Codex::Ed448N),
+                "M" => Ok(Codex::Ed448),
+                "E" => Ok(Codex::Blake3_256),
+                _ => err!(Error::UnexpectedCode(code.to_string())),
+            }
+        }
+    }
+}
+
+/// Common accessors and CESR text/binary domain conversions shared by all
+/// primitive (code, raw) pairs.
+pub trait Matter: Sized + Default {
+    fn code(&self) -> String;
+    fn raw(&self) -> Vec<u8>;
+    fn size(&self) -> u32;
+    fn set_code(&mut self, code: &str);
+    fn set_raw(&mut self, raw: &[u8]);
+    fn set_size(&mut self, size: u32);
+
+    fn new_with_code_and_raw(code: &str, raw: &[u8]) -> Result<Self> {
+        let size = tables::Codex::from_code(code)?.size();
+        if raw.len() != size as usize {
+            return err!(Error::Value(format!(
+                "invalid raw size for code '{code}': expected {size} bytes, got {}",
+                raw.len()
+            )));
+        }
+
+        let mut matter = Self::default();
+        matter.set_code(code);
+        matter.set_raw(raw);
+        matter.set_size(size);
+        Ok(matter)
+    }
+
+    fn qb64(&self) -> Result<String> {
+        use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+        Ok(format!("{}{}", self.code(), URL_SAFE_NO_PAD.encode(self.raw())))
+    }
+
+    fn qb64b(&self) -> Result<Vec<u8>> {
+        Ok(self.qb64()?.into_bytes())
+    }
+
+    fn new_with_qb64(qb64: &str) -> Result<Self> {
+        use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+
+        if qb64.is_empty() {
+            return err!(Error::Value("qb64 too short to carry a code".to_string()));
+        }
+        let (code, tail) = qb64.split_at(1);
+        let raw = URL_SAFE_NO_PAD.decode(tail).map_err(|e| Error::Value(e.to_string()))?;
+        Self::new_with_code_and_raw(code, &raw)
+    }
+
+    fn new_with_qb64b(qb64b: &[u8]) -> Result<Self> {
+        let qb64 = std::str::from_utf8(qb64b).map_err(|e| Error::Value(e.to_string()))?;
+        Self::new_with_qb64(qb64)
+    }
+
+    fn qb2(&self) -> Result<Vec<u8>> {
+        let code = self.code();
+        let raw = self.raw();
+        let mut qb2 = Vec::with_capacity(1 + code.len() + raw.len());
+        qb2.push(code.len() as u8);
+        qb2.extend_from_slice(code.as_bytes());
+        qb2.extend_from_slice(&raw);
+        Ok(qb2)
+    }
+
+    fn new_with_qb2(qb2: &[u8]) -> Result<Self> {
+        let code_len =
+            *qb2.first().ok_or_else(|| Error::Value("qb2 too short to carry a code".to_string()))?
+                as usize;
+        let code_end = 1 + code_len;
+        if qb2.len() < code_end {
+            return err!(Error::Value("qb2 truncated before end of code".to_string()));
+        }
+        let code =
+            std::str::from_utf8(&qb2[1..code_end]).map_err(|e| Error::Value(e.to_string()))?;
+        Self::new_with_code_and_raw(code, &qb2[code_end..])
+    }
+}
@@ -0,0 +1,2 @@
+pub mod matter;
+pub mod verfer;
@@ -8,11 +8,22 @@ pub struct Verfer {
     raw: Vec<u8>,
     code: String,
     size: u32,
+    // when true, reject high-S ECDSA secp256k1 signatures instead of
+    // normalizing them to their canonical low-S form before verifying
+    strict_256k1: bool,
+    // same as strict_256k1, but for ECDSA P-256 (secp256r1) signatures
+    strict_256r1: bool,
 }
 
 impl Default for Verfer {
     fn default() -> Self {
-        Verfer { raw: vec![], code: matter::Codex::Ed25519.code().to_string(), size: 0 }
+        Verfer {
+            raw: vec![],
+            code: matter::Codex::Ed25519.code().to_string(),
+            size: 0,
+            strict_256k1: false,
+            strict_256r1: false,
+        }
     }
 }
 
@@ -23,8 +34,14 @@ fn validate_code(code: &str) -> Result<()> {
             matter::Codex::Ed25519.code(),
             matter::Codex::ECDSA_256k1N.code(),
             matter::Codex::ECDSA_256k1.code(),
-            // matter::Codex::Ed448N.code(),
-            // matter::Codex::Ed448.code(),
+            matter::Codex::Ed448N.code(),
+            matter::Codex::Ed448.code(),
+            // 32-byte x-only secp256k1 public key (BIP340); matter::tables
+            // carries its raw size as 32 bytes, same as ECDSA_256k1's
+            // compressed point minus the leading parity octet
+            matter::Codex::ECDSA_256k1_Schnorr.code(),
+            matter::Codex::ECDSA_256r1N.code(),
+            matter::Codex::ECDSA_256r1.code(),
         ];
     }
 
@@ -59,6 +76,129 @@ impl Verfer {
         Ok(verfer)
     }
 
+    /// Returns the JWS (RFC 7518) `alg` identifier matching this key's
+    /// signature scheme, for handing CESR-anchored key material off to
+    /// standard JWT/JWS tooling.
+    pub fn jws_alg(&self) -> Result<&str> {
+        let ev = matter::Codex::from_code(&self.code())?;
+
+        match ev {
+            matter::Codex::Ed25519N | matter::Codex::Ed25519 => Ok("EdDSA"),
+            matter::Codex::Ed448N | matter::Codex::Ed448 => Ok("EdDSA"),
+            matter::Codex::ECDSA_256k1N | matter::Codex::ECDSA_256k1 => Ok("ES256K"),
+            matter::Codex::ECDSA_256r1N | matter::Codex::ECDSA_256r1 => Ok("ES256"),
+            _ => err!(Error::UnexpectedCode(format!(
+                "no JWS algorithm for code: code = '{}'",
+                ev.code()
+            ))),
+        }
+    }
+
+    /// Exports this key as a public JWK (RFC 7517) JSON string, decoding
+    /// `kty`/`crv`/`x`(/`y`) from the raw key bytes.
+    pub fn to_jwk(&self) -> Result<String> {
+        use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+
+        let ev = matter::Codex::from_code(&self.code())?;
+
+        match ev {
+            matter::Codex::Ed25519N | matter::Codex::Ed25519 => {
+                let x = URL_SAFE_NO_PAD.encode(self.raw());
+                Ok(format!(r#"{{"kty":"OKP","crv":"Ed25519","x":"{x}"}}"#))
+            }
+            matter::Codex::Ed448N | matter::Codex::Ed448 => {
+                let x = URL_SAFE_NO_PAD.encode(self.raw());
+                Ok(format!(r#"{{"kty":"OKP","crv":"Ed448","x":"{x}"}}"#))
+            }
+            matter::Codex::ECDSA_256k1N | matter::Codex::ECDSA_256k1 => {
+                use k256::elliptic_curve::sec1::ToEncodedPoint;
+
+                let public_key = k256::PublicKey::from_sec1_bytes(self.raw().as_slice())?;
+                let point = public_key.to_encoded_point(false);
+                let x = URL_SAFE_NO_PAD.encode(point.x().ok_or_else(|| {
+                    Error::Value("secp256k1 public key missing x coordinate".to_string())
+                })?);
+                let y = URL_SAFE_NO_PAD.encode(point.y().ok_or_else(|| {
+                    Error::Value("secp256k1 public key missing y coordinate".to_string())
+                })?);
+                Ok(format!(r#"{{"kty":"EC","crv":"secp256k1","x":"{x}","y":"{y}"}}"#))
+            }
+            // matter::tables carries ECDSA_256r1(N)'s raw size as 33 bytes,
+            // a compressed SEC1 point, same convention as ECDSA_256k1(N)
+            matter::Codex::ECDSA_256r1N | matter::Codex::ECDSA_256r1 => {
+                use p256::elliptic_curve::sec1::ToEncodedPoint;
+
+                let public_key = p256::PublicKey::from_sec1_bytes(self.raw().as_slice())?;
+                let point = public_key.to_encoded_point(false);
+                let x = URL_SAFE_NO_PAD.encode(point.x().ok_or_else(|| {
+                    Error::Value("P-256 public key missing x coordinate".to_string())
+                })?);
+                let y = URL_SAFE_NO_PAD.encode(point.y().ok_or_else(|| {
+                    Error::Value("P-256 public key missing y coordinate".to_string())
+                })?);
+                Ok(format!(r#"{{"kty":"EC","crv":"P-256","x":"{x}","y":"{y}"}}"#))
+            }
+            _ => err!(Error::UnexpectedCode(format!(
+                "no JWK mapping for code: code = '{}'",
+                ev.code()
+            ))),
+        }
+    }
+
+    /// When `strict` is true, ECDSA secp256k1 verification rejects
+    /// high-S signatures instead of normalizing them to low-S form.
+    /// Ed25519 is unaffected, as it has no equivalent malleability.
+    pub fn set_strict_256k1(&mut self, strict: bool) {
+        self.strict_256k1 = strict;
+    }
+
+    /// When `strict` is true, ECDSA P-256 verification rejects high-S
+    /// signatures instead of normalizing them to low-S form, mirroring
+    /// [`Verfer::set_strict_256k1`] for the secp256r1 curve.
+    pub fn set_strict_256r1(&mut self, strict: bool) {
+        self.strict_256r1 = strict;
+    }
+
+    pub fn verifier(&self) -> VerferVerifier {
+        VerferVerifier { verfer: self.clone(), ser: vec![] }
+    }
+
+    /// Verifies many Ed25519 (signature, message, key) triples at once.
+    /// Relies on `ed25519_dalek::verify_batch`, so the crate's `batch`
+    /// feature (which pulls in `merlin` and `curve25519-dalek`) must be
+    /// enabled wherever this is linked.
+    pub fn verify_batch(verfers: &[Verfer], sigs: &[&[u8]], sers: &[&[u8]]) -> Result<bool> {
+        use ed25519_dalek::{PublicKey, Signature};
+
+        if verfers.len() != sigs.len() || verfers.len() != sers.len() {
+            return err!(Error::Value("verfers, sigs and sers must be the same length".to_string()));
+        }
+
+        let mut public_keys = Vec::with_capacity(verfers.len());
+        let mut signatures = Vec::with_capacity(sigs.len());
+
+        for (verfer, sig) in verfers.iter().zip(sigs.iter()) {
+            let ev = matter::Codex::from_code(&verfer.code())?;
+            match ev {
+                matter::Codex::Ed25519N | matter::Codex::Ed25519 => (),
+                _ => {
+                    return err!(Error::UnexpectedCode(format!(
+                        "unexpected signature code for batch verification: code = '{}'",
+                        ev.code()
+                    )))
+                }
+            }
+
+            public_keys.push(PublicKey::from_bytes(verfer.raw().as_slice())?);
+            signatures.push(Signature::from_bytes(sig)?);
+        }
+
+        match ed25519_dalek::verify_batch(sers, &signatures, &public_keys) {
+            Ok(_) => Ok(true),
+            Err(_) => Ok(false),
+        }
+    }
+
     fn verify(&self, sig: &[u8], ser: &[u8]) -> Result<bool> {
         let ev = matter::Codex::from_code(&self.code())?;
 
@@ -67,8 +207,11 @@ impl Verfer {
             matter::Codex::Ed25519 => self.verify_ed25519_signature(sig, ser),
             matter::Codex::ECDSA_256k1N => self.verify_ecdsa_256k1_signature(sig, ser),
             matter::Codex::ECDSA_256k1 => self.verify_ecdsa_256k1_signature(sig, ser),
-            // matter::Codex::Ed448N => verify_ed448_signature(verfer, sig, ser)?,
-            // matter::Codex::Ed448 => verify_ed448_signature(verfer, sig, ser)?,
+            matter::Codex::Ed448N => self.verify_ed448_signature(sig, ser),
+            matter::Codex::Ed448 => self.verify_ed448_signature(sig, ser),
+            matter::Codex::ECDSA_256k1_Schnorr => self.verify_schnorr_256k1_signature(sig, ser),
+            matter::Codex::ECDSA_256r1N => self.verify_ecdsa_256r1_signature(sig, ser),
+            matter::Codex::ECDSA_256r1 => self.verify_ecdsa_256r1_signature(sig, ser),
             _ => err!(Error::UnexpectedCode(format!(
                 "unexpected signature code: code = '{}'",
                 ev.code()
@@ -88,17 +231,105 @@ impl Verfer {
         }
     }
 
+    fn verify_ed448_signature(&self, sig: &[u8], ser: &[u8]) -> Result<bool> {
+        use ed448_goldilocks::{signature::Verifier, Signature, VerifyingKey};
+
+        // VerifyingKey::from_bytes takes a fixed-size PointBytes ([u8; 57]),
+        // not an arbitrary-length slice, so a short/long raw is a data error
+        // rather than something the signature crate itself can reject.
+        let raw = self.raw();
+        let key_bytes: &[u8; 57] = match raw.as_slice().try_into() {
+            Ok(b) => b,
+            Err(_) => {
+                return err!(Error::Value(format!(
+                    "invalid Ed448 public key length: length = '{}'",
+                    raw.len()
+                )))
+            }
+        };
+        let public_key = match VerifyingKey::from_bytes(key_bytes) {
+            Ok(k) => k,
+            Err(e) => return err!(e),
+        };
+        let signature = match Signature::try_from(sig) {
+            Ok(s) => s,
+            Err(e) => return err!(e),
+        };
+
+        match public_key.verify(ser, &signature) {
+            Ok(_) => Ok(true),
+            Err(_) => Ok(false),
+        }
+    }
+
     fn verify_ecdsa_256k1_signature(&self, sig: &[u8], ser: &[u8]) -> Result<bool> {
         use k256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
 
         let public_key = VerifyingKey::from_sec1_bytes(self.raw().as_slice())?;
-        let signature = match Signature::try_from(sig) {
+        let mut signature = match Signature::try_from(sig) {
             Ok(s) => s,
             Err(e) => {
                 return err!(e);
             }
         };
 
+        // secp256k1 signatures are malleable: (r, s) and (r, n - s) both
+        // verify for the same message and key. Normalize to the
+        // canonical low-S form (or reject outright in strict mode) so a
+        // single message/key pair always yields one valid signature.
+        if let Some(normalized) = signature.normalize_s() {
+            if self.strict_256k1 {
+                return Ok(false);
+            }
+            signature = normalized;
+        }
+
+        match public_key.verify(ser, &signature) {
+            Ok(_) => Ok(true),
+            Err(_) => Ok(false),
+        }
+    }
+
+    // k256's `schnorr` feature gates this module
+    fn verify_schnorr_256k1_signature(&self, sig: &[u8], ser: &[u8]) -> Result<bool> {
+        use k256::schnorr::{signature::Verifier, Signature, VerifyingKey};
+
+        let public_key = VerifyingKey::from_bytes(self.raw().as_slice())?;
+        if sig.len() != Signature::BYTE_SIZE {
+            return err!(Error::Value(format!(
+                "invalid schnorr signature size: expected {} bytes, got {}",
+                Signature::BYTE_SIZE,
+                sig.len()
+            )));
+        }
+        let signature = Signature::try_from(sig)?;
+
+        match public_key.verify(ser, &signature) {
+            Ok(_) => Ok(true),
+            Err(_) => Ok(false),
+        }
+    }
+
+    fn verify_ecdsa_256r1_signature(&self, sig: &[u8], ser: &[u8]) -> Result<bool> {
+        use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+
+        let public_key = VerifyingKey::from_sec1_bytes(self.raw().as_slice())?;
+        let mut signature = match Signature::try_from(sig) {
+            Ok(s) => s,
+            Err(e) => {
+                return err!(e);
+            }
+        };
+
+        // P-256 signatures are malleable in the same way secp256k1's are;
+        // apply the same low-S normalize-or-reject policy.
+        if let Some(normalized) = signature.normalize_s() {
+            if self.strict_256r1 {
+                return Ok(false);
+            }
+            signature = normalized;
+        }
+
         match public_key.verify(ser, &signature) {
             Ok(_) => Ok(true),
             Err(_) => Ok(false),
@@ -132,9 +363,23 @@ impl Matter for Verfer {
     }
 }
 
-// fn verify_ed448_signature(verfer: &Matter, sig: &[u8], ser: &[u8]) -> Result<bool> {
-//     Ok(true)
-// }
+/// Incremental verifier that accumulates a serialized message in chunks
+/// before verifying it against a signature, for callers that would
+/// otherwise have to assemble one contiguous buffer themselves.
+pub struct VerferVerifier {
+    verfer: Verfer,
+    ser: Vec<u8>,
+}
+
+impl VerferVerifier {
+    pub fn update(&mut self, data: &[u8]) {
+        self.ser.extend_from_slice(data);
+    }
+
+    pub fn verify(self, sig: &[u8]) -> Result<bool> {
+        self.verfer.verify(sig, &self.ser)
+    }
+}
 
 #[cfg(test)]
 mod test_verfer {
@@ -208,11 +453,11 @@ mod test_verfer {
         let bad_ser = hex!("e1be4d7a8ab5560aa4199eea339849ba8e293d55ca0a81006726d184519e647f"
                                      "5b49b82f805a538c68915c1ae8035c900fd1d4b13902920fd05e1450822f36df");
 
-        let mut csprng = rand::rngs::OsRng::default();
+        let mut csprng = rand::rngs::OsRng;
         let keypair = ed25519_dalek::Keypair::generate(&mut csprng);
 
         let sig = keypair.sign(&ser).to_bytes();
-        let mut bad_sig = sig.clone();
+        let mut bad_sig = sig;
         bad_sig[0] ^= 0xff;
 
         let raw = keypair.public.as_bytes();
@@ -224,7 +469,7 @@ mod test_verfer {
         assert!(m.verify(&[], &ser).is_err());
 
         // exercise control flows for non-transferrable variant
-        m.set_code(&matter::Codex::Ed25519N.code());
+        m.set_code(matter::Codex::Ed25519N.code());
         assert!(m.verify(&sig, &ser).unwrap());
         assert!(!m.verify(&bad_sig, &ser).unwrap());
         assert!(!m.verify(&sig, &bad_ser).unwrap());
@@ -242,7 +487,7 @@ mod test_verfer {
         let private_key = SigningKey::random(&mut csprng);
 
         let sig = <SigningKey as Signer<Signature>>::sign(&private_key, &ser).to_bytes();
-        let mut bad_sig = sig.clone();
+        let mut bad_sig = sig;
         bad_sig[0] ^= 0xff;
 
         let public_key = VerifyingKey::from(private_key);
@@ -254,20 +499,307 @@ mod test_verfer {
         assert!(!m.verify(&sig, &bad_ser).unwrap());
         assert!(m.verify(&[], &ser).is_err());
 
-        m.set_code(&matter::Codex::ECDSA_256k1N.code());
+        m.set_code(matter::Codex::ECDSA_256k1N.code());
+        assert!(m.verify(&sig, &ser).unwrap());
+        assert!(!m.verify(&bad_sig, &ser).unwrap());
+        assert!(!m.verify(&sig, &bad_ser).unwrap());
+    }
+
+    #[test]
+    fn test_verify_ecdsa_256k1_low_s() {
+        use k256::ecdsa::{signature::Signer, Signature, SigningKey, VerifyingKey};
+        use k256::Scalar;
+
+        let ser = hex!("e1be4d7a8ab5560aa4199eea339849ba8e293d55ca0a81006726d184519e647f"
+                                 "5b49b82f805a538c68915c1ae8035c900fd1d4b13902920fd05e1450822f36de");
+
+        let mut csprng = rand_core::OsRng;
+        let private_key = SigningKey::random(&mut csprng);
+        let sig = <SigningKey as Signer<Signature>>::sign(&private_key, &ser);
+
+        let public_key = VerifyingKey::from(&private_key);
+        let raw = public_key.to_encoded_point(true).to_bytes();
+
+        // derive the low-S signature and its high-S malleated counterpart
+        let low_sig = sig.normalize_s().unwrap_or(sig);
+        let high_s: Scalar = -*low_sig.s().as_ref();
+        let high_sig = Signature::from_scalars(*low_sig.r().as_ref(), high_s).unwrap();
+
+        let mut m = Verfer::new_with_code_and_raw(matter::Codex::ECDSA_256k1.code(), &raw).unwrap();
+
+        // permissive (default): both the canonical and malleated signature verify
+        assert!(m.verify(&low_sig.to_bytes(), &ser).unwrap());
+        assert!(m.verify(&high_sig.to_bytes(), &ser).unwrap());
+
+        // strict: only the canonical low-S signature verifies
+        m.set_strict_256k1(true);
+        assert!(m.verify(&low_sig.to_bytes(), &ser).unwrap());
+        assert!(!m.verify(&high_sig.to_bytes(), &ser).unwrap());
+    }
+
+    #[test]
+    fn test_verify_schnorr_256k1() {
+        use k256::schnorr::{signature::Signer, SigningKey};
+
+        let ser = hex!("e1be4d7a8ab5560aa4199eea339849ba8e293d55ca0a81006726d184519e647f"
+                                 "5b49b82f805a538c68915c1ae8035c900fd1d4b13902920fd05e1450822f36de");
+        let bad_ser = hex!("badd");
+
+        let mut csprng = rand_core::OsRng;
+        let private_key = SigningKey::random(&mut csprng);
+
+        let sig = private_key.sign(&ser).to_bytes();
+        let mut bad_sig = sig;
+        bad_sig[0] ^= 0xff;
+
+        let public_key = private_key.verifying_key();
+        let raw = public_key.to_bytes();
+
+        let m =
+            Verfer::new_with_code_and_raw(matter::Codex::ECDSA_256k1_Schnorr.code(), &raw).unwrap();
+        assert!(m.verify(&sig, &ser).unwrap());
+        assert!(!m.verify(&bad_sig, &ser).unwrap());
+        assert!(!m.verify(&sig, &bad_ser).unwrap());
+        assert!(m.verify(&[], &ser).is_err());
+    }
+
+    #[test]
+    fn test_verify_ed448() {
+        use ed448_goldilocks::{elliptic_curve::Generate, signature::Signer, SigningKey};
+
+        let ser = hex!("e1be4d7a8ab5560aa4199eea339849ba8e293d55ca0a81006726d184519e647f"
+                                 "5b49b82f805a538c68915c1ae8035c900fd1d4b13902920fd05e1450822f36de");
+        let bad_ser = hex!("e1be4d7a8ab5560aa4199eea339849ba8e293d55ca0a81006726d184519e647f"
+                                     "5b49b82f805a538c68915c1ae8035c900fd1d4b13902920fd05e1450822f36df");
+
+        let signing_key = SigningKey::generate();
+
+        let sig = signing_key.sign(&ser).to_bytes();
+        let mut bad_sig = sig;
+        bad_sig[0] ^= 0xff;
+
+        let verifying_key = signing_key.verifying_key();
+        let raw = verifying_key.to_bytes();
+
+        let mut m = Verfer::new_with_code_and_raw(matter::Codex::Ed448.code(), &raw).unwrap();
+        assert!(m.verify(&sig, &ser).unwrap());
+        assert!(!m.verify(&bad_sig, &ser).unwrap());
+        assert!(!m.verify(&sig, &bad_ser).unwrap());
+        assert!(m.verify(&[], &ser).is_err());
+
+        // exercise control flows for non-transferrable variant
+        m.set_code(matter::Codex::Ed448N.code());
         assert!(m.verify(&sig, &ser).unwrap());
         assert!(!m.verify(&bad_sig, &ser).unwrap());
         assert!(!m.verify(&sig, &bad_ser).unwrap());
     }
 
+    #[test]
+    fn test_verify_batch() {
+        use ed25519_dalek::Signer;
+
+        let sers: Vec<Vec<u8>> = vec![
+            hex!("e1be4d7a8ab5560aa4199eea339849ba8e293d55ca0a81006726d184519e647f"
+                 "5b49b82f805a538c68915c1ae8035c900fd1d4b13902920fd05e1450822f36de")
+                .to_vec(),
+            hex!("f1be4d7a8ab5560aa4199eea339849ba8e293d55ca0a81006726d184519e647f"
+                 "5b49b82f805a538c68915c1ae8035c900fd1d4b13902920fd05e1450822f36df")
+                .to_vec(),
+        ];
+
+        let mut csprng = rand::rngs::OsRng;
+        let mut verfers = vec![];
+        let mut sigs = vec![];
+
+        for ser in &sers {
+            let keypair = ed25519_dalek::Keypair::generate(&mut csprng);
+            let sig = keypair.sign(ser).to_bytes();
+            let verfer =
+                Verfer::new_with_code_and_raw(matter::Codex::Ed25519.code(), keypair.public.as_bytes())
+                    .unwrap();
+            verfers.push(verfer);
+            sigs.push(sig);
+        }
+
+        let sig_refs: Vec<&[u8]> = sigs.iter().map(|s| s.as_slice()).collect();
+        let ser_refs: Vec<&[u8]> = sers.iter().map(|s| s.as_slice()).collect();
+
+        assert!(Verfer::verify_batch(&verfers, &sig_refs, &ser_refs).unwrap());
+
+        let mut bad_sig_refs = sig_refs.clone();
+        let mut bad_sig = sigs[0];
+        bad_sig[0] ^= 0xff;
+        bad_sig_refs[0] = &bad_sig;
+        assert!(!Verfer::verify_batch(&verfers, &bad_sig_refs, &ser_refs).unwrap());
+
+        assert!(Verfer::verify_batch(&verfers, &sig_refs[..1], &ser_refs).is_err());
+
+        let mut bad_verfers = verfers.clone();
+        bad_verfers[0].set_code(matter::Codex::ECDSA_256k1.code());
+        assert!(Verfer::verify_batch(&bad_verfers, &sig_refs, &ser_refs).is_err());
+    }
+
+    #[test]
+    fn test_verifier_streaming() {
+        use ed25519_dalek::Signer;
+
+        let ser = hex!("e1be4d7a8ab5560aa4199eea339849ba8e293d55ca0a81006726d184519e647f"
+                                 "5b49b82f805a538c68915c1ae8035c900fd1d4b13902920fd05e1450822f36de");
+
+        let mut csprng = rand::rngs::OsRng;
+        let keypair = ed25519_dalek::Keypair::generate(&mut csprng);
+
+        let sig = keypair.sign(&ser).to_bytes();
+        let mut bad_sig = sig;
+        bad_sig[0] ^= 0xff;
+
+        let raw = keypair.public.as_bytes();
+        let m = Verfer::new_with_code_and_raw(matter::Codex::Ed25519.code(), raw).unwrap();
+
+        let mut verifier = m.verifier();
+        verifier.update(&ser[..16]);
+        verifier.update(&ser[16..]);
+        assert!(verifier.verify(&sig).unwrap());
+
+        let mut verifier = m.verifier();
+        verifier.update(&ser);
+        assert!(!verifier.verify(&bad_sig).unwrap());
+    }
+
     #[test]
     fn test_unhappy_paths() {
         assert!(Verfer {
             code: matter::Codex::Blake3_256.code().to_string(),
             raw: vec![],
-            size: 0
+            size: 0,
+            strict_256k1: false,
+            strict_256r1: false,
         }
         .verify(&[], &[])
         .is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_verify_ecdsa_256r1() {
+        use p256::ecdsa::{signature::Signer, Signature, SigningKey, VerifyingKey};
+        use p256::Scalar;
+
+        let ser = hex!("e1be4d7a8ab5560aa4199eea339849ba8e293d55ca0a81006726d184519e647f"
+                                 "5b49b82f805a538c68915c1ae8035c900fd1d4b13902920fd05e1450822f36de");
+        let bad_ser = hex!("badd");
+
+        let mut csprng = rand_core::OsRng;
+        let private_key = SigningKey::random(&mut csprng);
+
+        let sig = <SigningKey as Signer<Signature>>::sign(&private_key, &ser);
+        let mut bad_sig = sig.to_bytes();
+        bad_sig[0] ^= 0xff;
+
+        let public_key = VerifyingKey::from(&private_key);
+        let raw = public_key.to_encoded_point(true).to_bytes();
+
+        let mut m =
+            Verfer::new_with_code_and_raw(matter::Codex::ECDSA_256r1.code(), &raw).unwrap();
+        assert!(m.verify(&sig.to_bytes(), &ser).unwrap());
+        assert!(!m.verify(&bad_sig, &ser).unwrap());
+        assert!(!m.verify(&sig.to_bytes(), &bad_ser).unwrap());
+        assert!(m.verify(&[], &ser).is_err());
+
+        m.set_code(matter::Codex::ECDSA_256r1N.code());
+        assert!(m.verify(&sig.to_bytes(), &ser).unwrap());
+        assert!(!m.verify(&bad_sig, &ser).unwrap());
+
+        // derive the low-S signature and its high-S malleated counterpart
+        let low_sig = sig.normalize_s().unwrap_or(sig);
+        let high_s: Scalar = -*low_sig.s().as_ref();
+        let high_sig = Signature::from_scalars(*low_sig.r().as_ref(), high_s).unwrap();
+
+        // permissive (default): both the canonical and malleated signature verify
+        assert!(m.verify(&low_sig.to_bytes(), &ser).unwrap());
+        assert!(m.verify(&high_sig.to_bytes(), &ser).unwrap());
+
+        // strict: only the canonical low-S signature verifies
+        m.set_strict_256r1(true);
+        assert!(m.verify(&low_sig.to_bytes(), &ser).unwrap());
+        assert!(!m.verify(&high_sig.to_bytes(), &ser).unwrap());
+    }
+
+    #[test]
+    fn test_jws_alg() {
+        let raw = hex!("0123456789abcdef00001111222233334444555566667777888899990000aaaa");
+
+        let m = Verfer::new_with_code_and_raw(matter::Codex::Ed25519.code(), &raw).unwrap();
+        assert_eq!(m.jws_alg().unwrap(), "EdDSA");
+
+        let raw_448 = hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122"
+                                     "232425262728292a2b2c2d2e2f303132333435363738");
+        let m = Verfer::new_with_code_and_raw(matter::Codex::Ed448.code(), &raw_448).unwrap();
+        assert_eq!(m.jws_alg().unwrap(), "EdDSA");
+
+        let raw_33 = hex!("020123456789abcdef00001111222233334444555566667777888899990000aaaa");
+
+        let m = Verfer::new_with_code_and_raw(matter::Codex::ECDSA_256k1.code(), &raw_33).unwrap();
+        assert_eq!(m.jws_alg().unwrap(), "ES256K");
+
+        let m = Verfer::new_with_code_and_raw(matter::Codex::ECDSA_256r1.code(), &raw_33).unwrap();
+        assert_eq!(m.jws_alg().unwrap(), "ES256");
+
+        let m = Verfer::new_with_code_and_raw(matter::Codex::ECDSA_256k1_Schnorr.code(), &raw)
+            .unwrap();
+        assert!(m.jws_alg().is_err());
+    }
+
+    // extracts and base64url-decodes a `"field":"..."` member from a JWK
+    // JSON string, so tests can check the emitted coordinates round-trip
+    // back to the source key bytes rather than just checking for substrings
+    fn decode_jwk_field(jwk: &str, field: &str) -> Vec<u8> {
+        use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+
+        let needle = format!(r#""{field}":""#);
+        let start = jwk.find(&needle).unwrap() + needle.len();
+        let end = start + jwk[start..].find('"').unwrap();
+        URL_SAFE_NO_PAD.decode(&jwk[start..end]).unwrap()
+    }
+
+    #[test]
+    fn test_to_jwk() {
+        let raw = hex!("0123456789abcdef00001111222233334444555566667777888899990000aaaa");
+        let m = Verfer::new_with_code_and_raw(matter::Codex::Ed25519.code(), &raw).unwrap();
+        let jwk = m.to_jwk().unwrap();
+        assert!(jwk.contains(r#""kty":"OKP""#));
+        assert!(jwk.contains(r#""crv":"Ed25519""#));
+        assert_eq!(decode_jwk_field(&jwk, "x"), raw);
+
+        let raw_448 = hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122"
+                                     "232425262728292a2b2c2d2e2f303132333435363738");
+        let m = Verfer::new_with_code_and_raw(matter::Codex::Ed448.code(), &raw_448).unwrap();
+        let jwk = m.to_jwk().unwrap();
+        assert!(jwk.contains(r#""kty":"OKP""#));
+        assert!(jwk.contains(r#""crv":"Ed448""#));
+        assert_eq!(decode_jwk_field(&jwk, "x"), raw_448);
+
+        use k256::ecdsa::SigningKey;
+        let private_key = SigningKey::random(&mut rand_core::OsRng);
+        let public_key = k256::ecdsa::VerifyingKey::from(private_key);
+        let raw = public_key.to_encoded_point(true).to_bytes();
+        let m = Verfer::new_with_code_and_raw(matter::Codex::ECDSA_256k1.code(), &raw).unwrap();
+        let jwk = m.to_jwk().unwrap();
+        assert!(jwk.contains(r#""kty":"EC""#));
+        assert!(jwk.contains(r#""crv":"secp256k1""#));
+        let uncompressed = public_key.to_encoded_point(false);
+        assert_eq!(decode_jwk_field(&jwk, "x"), AsRef::<[u8]>::as_ref(uncompressed.x().unwrap()));
+        assert_eq!(decode_jwk_field(&jwk, "y"), AsRef::<[u8]>::as_ref(uncompressed.y().unwrap()));
+
+        use p256::ecdsa::SigningKey as P256SigningKey;
+        let private_key = P256SigningKey::random(&mut rand_core::OsRng);
+        let public_key = p256::ecdsa::VerifyingKey::from(private_key);
+        let raw = public_key.to_encoded_point(true).to_bytes();
+        let m = Verfer::new_with_code_and_raw(matter::Codex::ECDSA_256r1.code(), &raw).unwrap();
+        let jwk = m.to_jwk().unwrap();
+        assert!(jwk.contains(r#""kty":"EC""#));
+        assert!(jwk.contains(r#""crv":"P-256""#));
+        let uncompressed = public_key.to_encoded_point(false);
+        assert_eq!(decode_jwk_field(&jwk, "x"), AsRef::<[u8]>::as_ref(uncompressed.x().unwrap()));
+        assert_eq!(decode_jwk_field(&jwk, "y"), AsRef::<[u8]>::as_ref(uncompressed.y().unwrap()));
+    }
+}
@@ -0,0 +1,54 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    UnexpectedCode(String),
+    Value(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnexpectedCode(msg) => write!(f, "unexpected code: {msg}"),
+            Error::Value(msg) => write!(f, "value error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ed25519_dalek::SignatureError> for Error {
+    fn from(e: ed25519_dalek::SignatureError) -> Self {
+        Error::Value(e.to_string())
+    }
+}
+
+impl From<signature::Error> for Error {
+    fn from(e: signature::Error) -> Self {
+        Error::Value(e.to_string())
+    }
+}
+
+// ed448-goldilocks pulls in its own major version of the `signature`
+// crate, distinct from the one k256/p256/ed25519-dalek resolve to, so it
+// needs its own conversion even though the error shape is the same.
+impl From<ed448_goldilocks::signature::Error> for Error {
+    fn from(e: ed448_goldilocks::signature::Error) -> Self {
+        Error::Value(e.to_string())
+    }
+}
+
+impl From<k256::elliptic_curve::Error> for Error {
+    fn from(e: k256::elliptic_curve::Error) -> Self {
+        Error::Value(e.to_string())
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+macro_rules! err {
+    ($e:expr) => {
+        Err($crate::error::Error::from($e))
+    };
+}
+pub(crate) use err;